@@ -0,0 +1,99 @@
+// Copyright (c) 2015 Jeff Belgum
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the Software without restriction, including without
+// limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+extern crate num;
+
+use num::Float;
+
+use super::stats_ as stats;
+
+/// The result of an ordinary least squares fit of `ys` onto `xs`, holding the fitted
+/// line `y = slope*x + intercept` and its coefficient of determination.
+/// (reference)[http://en.wikipedia.org/wiki/Simple_linear_regression]
+pub struct Regression<T> {
+    pub slope: T,
+    pub intercept: T,
+    pub r_squared: T,
+}
+
+/// The Pearson product-moment correlation coefficient between two equal-length series,
+/// `r = cov(x, y) / (sigma_x * sigma_y)`.
+/// (reference)[http://en.wikipedia.org/wiki/Pearson_correlation_coefficient]
+pub fn pearson_correlation<T>(xs: &[T], ys: &[T]) -> T
+    where T: Float
+{
+    assert!(xs.len() == ys.len(), "pearson_correlation requires equal length inputs");
+    let n: T = num::cast(xs.len()).unwrap();
+    let xbar = stats::mean(xs);
+    let ybar = stats::mean(ys);
+
+    let covariance = xs.iter().zip(ys.iter())
+        .map(|(&x, &y)| (x - xbar) * (y - ybar))
+        .fold(T::zero(), |acc, elem| acc + elem) / n;
+    let sigma_x = stats::population_standard_deviation(xs, Some(xbar));
+    let sigma_y = stats::population_standard_deviation(ys, Some(ybar));
+    covariance / (sigma_x * sigma_y)
+}
+
+/// Fit `ys` onto `xs` by ordinary least squares, returning the slope, intercept and
+/// coefficient of determination. (reference)[http://en.wikipedia.org/wiki/Simple_linear_regression]
+pub fn linear_regression<T>(xs: &[T], ys: &[T]) -> Regression<T>
+    where T: Float
+{
+    assert!(xs.len() == ys.len(), "linear_regression requires equal length inputs");
+    let xbar = stats::mean(xs);
+    let ybar = stats::mean(ys);
+
+    let numerator = xs.iter().zip(ys.iter())
+        .map(|(&x, &y)| (x - xbar) * (y - ybar))
+        .fold(T::zero(), |acc, elem| acc + elem);
+    let denominator = xs.iter()
+        .map(|&x| (x - xbar) * (x - xbar))
+        .fold(T::zero(), |acc, elem| acc + elem);
+
+    let slope = numerator / denominator;
+    let intercept = ybar - slope * xbar;
+    let r = pearson_correlation(xs, ys);
+    Regression { slope, intercept, r_squared: r * r }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_linear_regression() {
+        // a perfect line y = 2x + 1: slope 2, intercept 1, r^2 exactly 1.
+        let xs = vec![1.0, 2.0, 3.0, 4.0];
+        let ys = vec![3.0, 5.0, 7.0, 9.0];
+        let epsilon = 1e-9;
+        let fit = linear_regression(&xs, &ys);
+        assert!((fit.slope - 2.0).abs() < epsilon);
+        assert!((fit.intercept - 1.0).abs() < epsilon);
+        assert!((fit.r_squared - 1.0).abs() < epsilon);
+    }
+
+    #[test]
+    fn test_pearson_correlation() {
+        let xs = vec![1.0, 2.0, 3.0, 4.0];
+        let ys = vec![3.0, 5.0, 7.0, 9.0];
+        assert!((pearson_correlation(&xs, &ys) - 1.0).abs() < 1e-9);
+        // perfectly anticorrelated series.
+        let zs = vec![9.0, 7.0, 5.0, 3.0];
+        assert!((pearson_correlation(&xs, &zs) + 1.0).abs() < 1e-9);
+    }
+}
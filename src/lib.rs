@@ -24,6 +24,10 @@ extern crate num;
 
 mod univariate_;
 mod stats_;
+mod resample_;
+mod outliers_;
+mod bivariate_;
+mod running_;
 
 pub mod univariate {
     pub use univariate_::{
@@ -39,15 +43,46 @@ pub mod univariate {
         pkurtosis,
         standard_error_mean,
         standard_error_skewness,
-        standard_error_kurtosis
+        standard_error_kurtosis,
+        KernelDensityEstimator
     };
 }
 
+pub mod resample {
+    pub use resample_::{
+        BootstrapDistribution,
+        bootstrap
+    };
+}
+
+pub mod outliers {
+    pub use outliers_::{
+        OutlierClassification,
+        tukey_outliers
+    };
+}
+
+pub mod bivariate {
+    pub use bivariate_::{
+        Regression,
+        pearson_correlation,
+        linear_regression
+    };
+}
+
+pub mod running {
+    pub use running_::RunningStats;
+}
+
 pub use univariate::mode;
 pub use stats_::{
     Degree,
     mean,
     median,
+    select_nth,
+    integer_mean,
+    integer_mean_round,
+    integer_median,
     variance,
     population_variance,
     standard_deviation,
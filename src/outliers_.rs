@@ -0,0 +1,114 @@
+// Copyright (c) 2015 Jeff Belgum
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the Software without restriction, including without
+// limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+extern crate num;
+
+use num::Float;
+
+use super::stats_ as stats;
+
+/// A Tukey-fence classification of a data sample into five buckets, holding the fences that
+/// were applied along with the counts and index lists of the data points in each bucket.
+/// (reference)[http://en.wikipedia.org/wiki/Outlier#Tukey's_fences]
+pub struct OutlierClassification<T> {
+    pub low_severe_fence: T,
+    pub low_mild_fence: T,
+    pub high_mild_fence: T,
+    pub high_severe_fence: T,
+    pub low_severe: Vec<usize>,
+    pub low_mild: Vec<usize>,
+    pub normal: Vec<usize>,
+    pub high_mild: Vec<usize>,
+    pub high_severe: Vec<usize>,
+}
+
+impl<T> OutlierClassification<T> {
+    /// The number of data points below the severe low fence.
+    pub fn low_severe_count(&self) -> usize { self.low_severe.len() }
+    /// The number of data points between the mild and severe low fences.
+    pub fn low_mild_count(&self) -> usize { self.low_mild.len() }
+    /// The number of data points inside the mild fences.
+    pub fn normal_count(&self) -> usize { self.normal.len() }
+    /// The number of data points between the mild and severe high fences.
+    pub fn high_mild_count(&self) -> usize { self.high_mild.len() }
+    /// The number of data points above the severe high fence.
+    pub fn high_severe_count(&self) -> usize { self.high_severe.len() }
+}
+
+/// Classify each datum using Tukey's fences. With `Q1` and `Q3` the first and third
+/// quartiles and `IQR = Q3 - Q1`, a point is flagged when it falls outside
+/// `Q1 - k*IQR` or `Q3 + k*IQR` for the mild (`k_mild`, conventionally 1.5) and severe
+/// (`k_severe`, conventionally 3.0) multipliers.
+/// (reference)[http://en.wikipedia.org/wiki/Outlier#Tukey's_fences]
+pub fn tukey_outliers<T>(v: &[T], k_mild: T, k_severe: T) -> OutlierClassification<T>
+    where T: Float
+{
+    assert!(!v.is_empty(), "tukey_outliers requires at least one data point");
+    let q1 = stats::percentile(v, num::cast(25).unwrap());
+    let q3 = stats::percentile(v, num::cast(75).unwrap());
+    let iqr = q3 - q1;
+
+    let low_mild_fence = q1 - k_mild * iqr;
+    let low_severe_fence = q1 - k_severe * iqr;
+    let high_mild_fence = q3 + k_mild * iqr;
+    let high_severe_fence = q3 + k_severe * iqr;
+
+    let mut classification = OutlierClassification {
+        low_severe_fence,
+        low_mild_fence,
+        high_mild_fence,
+        high_severe_fence,
+        low_severe: Vec::new(),
+        low_mild: Vec::new(),
+        normal: Vec::new(),
+        high_mild: Vec::new(),
+        high_severe: Vec::new(),
+    };
+
+    for (i, &x) in v.iter().enumerate() {
+        if x < low_severe_fence {
+            classification.low_severe.push(i);
+        } else if x < low_mild_fence {
+            classification.low_mild.push(i);
+        } else if x > high_severe_fence {
+            classification.high_severe.push(i);
+        } else if x > high_mild_fence {
+            classification.high_mild.push(i);
+        } else {
+            classification.normal.push(i);
+        }
+    }
+
+    classification
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tukey_outliers() {
+        // index 10 (the value 100) sits far above the high fences; everything else is normal.
+        let v = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 100.0];
+        let c = tukey_outliers(&v, 1.5, 3.0);
+        assert!(c.high_severe.contains(&10));
+        assert_eq!(c.normal_count(), v.len() - 1);
+        let total = c.low_severe_count() + c.low_mild_count() + c.normal_count()
+            + c.high_mild_count() + c.high_severe_count();
+        assert_eq!(total, v.len());
+    }
+}
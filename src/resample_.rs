@@ -0,0 +1,92 @@
+// Copyright (c) 2015 Jeff Belgum
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the Software without restriction, including without
+// limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+extern crate rand;
+extern crate num;
+
+use num::Float;
+
+use super::stats_ as stats;
+
+/// The distribution of a statistic computed over many bootstrap resamples.
+/// Holds the resampled values so that nonparametric error bars can be read off
+/// with the percentile method. (reference)[http://en.wikipedia.org/wiki/Bootstrapping_(statistics)]
+pub struct BootstrapDistribution<T> {
+    resampled: Vec<T>,
+}
+
+impl<T> BootstrapDistribution<T>
+    where T: Float
+{
+    /// The standard error is the standard deviation of the resampled statistics. Requires the
+    /// distribution to hold at least two resamples (see `bootstrap`'s `nresamples`).
+    pub fn standard_error(&self) -> T {
+        stats::standard_deviation(&self.resampled, None)
+    }
+
+    /// A `confidence`-level interval estimated by the percentile method: the resampled
+    /// statistics are sorted and the values at the `(1-confidence)/2` and `(1+confidence)/2`
+    /// percentiles are returned as the lower and upper bounds.
+    pub fn confidence_interval(&self, confidence: T) -> (T, T) {
+        let hundred: T = num::cast(100).unwrap();
+        let two = T::one() + T::one();
+        let lower = ((T::one() - confidence) / two) * hundred;
+        let upper = ((T::one() + confidence) / two) * hundred;
+        (stats::percentile(&self.resampled, lower), stats::percentile(&self.resampled, upper))
+    }
+}
+
+/// Draw `nresamples` samples of size `v.len()` with replacement, apply the `statistic`
+/// closure to each resample, and collect the results into a `BootstrapDistribution`.
+/// `nresamples` must be at least two so that `standard_error`/`confidence_interval` are
+/// well defined. (reference)[http://en.wikipedia.org/wiki/Bootstrapping_(statistics)]
+pub fn bootstrap<T, F>(v: &[T], nresamples: usize, statistic: F) -> BootstrapDistribution<T>
+    where T: Float,
+          F: Fn(&[T]) -> T
+{
+    assert!(!v.is_empty(), "bootstrap requires at least one data point");
+    assert!(nresamples > 1, "bootstrap requires at least two resamples");
+    let mut resampled = Vec::with_capacity(nresamples);
+    let mut sample = Vec::with_capacity(v.len());
+    for _ in 0..nresamples {
+        sample.clear();
+        for _ in 0..v.len() {
+            sample.push(v[rand::random::<usize>() % v.len()]);
+        }
+        resampled.push(statistic(&sample));
+    }
+    BootstrapDistribution { resampled }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::stats_ as stats;
+
+    #[test]
+    fn test_bootstrap_constant() {
+        // every resample of a constant sample yields the same statistic, so the standard
+        // error collapses to zero and the confidence interval to a point.
+        let v = vec![5.0; 50];
+        let dist = bootstrap(&v, 200, stats::mean);
+        let epsilon = 1e-9;
+        assert!(dist.standard_error().abs() < epsilon);
+        let (lo, hi) = dist.confidence_interval(0.95);
+        assert!((lo - 5.0).abs() < epsilon);
+        assert!((hi - 5.0).abs() < epsilon);
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) 2015 Jeff Belgum
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of this software and associated
+// documentation files (the "Software"), to deal in the Software without restriction, including without
+// limitation the rights to use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so, subject to the following
+// conditions:
+//
+// The above copyright notice and this permission notice shall be included in all copies or substantial portions
+// of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED
+// TO THE WARRANTIES OF MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT
+// SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER
+// DEALINGS IN THE SOFTWARE.
+
+extern crate num;
+
+use num::Float;
+
+/// A single-pass accumulator that maintains the running central moments M1 through M4 via
+/// Welford/Terriberry's update recurrences, so the mean, population variance, skewness and
+/// kurtosis of a stream of unknown length can be read off in O(1).
+/// (reference)[http://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Higher-order_statistics]
+pub struct RunningStats<T> {
+    n: usize,
+    m1: T,
+    m2: T,
+    m3: T,
+    m4: T,
+}
+
+impl<T> Default for RunningStats<T>
+    where T: Float
+{
+    fn default() -> RunningStats<T> {
+        RunningStats { n: 0, m1: T::zero(), m2: T::zero(), m3: T::zero(), m4: T::zero() }
+    }
+}
+
+impl<T> RunningStats<T>
+    where T: Float
+{
+    /// Create an empty accumulator.
+    pub fn new() -> RunningStats<T> {
+        RunningStats::default()
+    }
+
+    /// Number of values pushed so far.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Whether no values have been pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Incorporate a new value, updating the running central moments in place.
+    pub fn push(&mut self, x: T) {
+        self.n += 1;
+        let n: T = num::cast(self.n).unwrap();
+        let delta = x - self.m1;
+        let delta_n = delta / n;
+        let term = delta * delta_n * (n - T::one());
+        let two = num::cast::<f32, T>(2.0).unwrap();
+        let three = num::cast::<f32, T>(3.0).unwrap();
+        let four = num::cast::<f32, T>(4.0).unwrap();
+        let six = num::cast::<f32, T>(6.0).unwrap();
+
+        self.m4 = self.m4
+            + term * delta_n * delta_n * (n * n - three * n + three)
+            + six * delta_n * delta_n * self.m2
+            - four * delta_n * self.m3;
+        self.m3 = self.m3
+            + term * delta_n * (n - two)
+            - three * delta_n * self.m2;
+        self.m2 = self.m2 + term;
+        self.m1 = self.m1 + delta_n;
+    }
+
+    /// The running arithmetic mean.
+    pub fn mean(&self) -> T {
+        self.m1
+    }
+
+    /// The running population variance, `M2 / n`. Requires at least one value.
+    pub fn population_variance(&self) -> T {
+        assert!(self.n > 0, "population variance requires at least one data point");
+        let n: T = num::cast(self.n).unwrap();
+        self.m2 / n
+    }
+
+    /// The running population skewness, `sqrt(n) * M3 / M2^(3/2)`. Requires at least one
+    /// value; constant samples have zero variance and yield `NaN`.
+    pub fn skewness(&self) -> T {
+        assert!(self.n > 0, "skewness requires at least one data point");
+        let n: T = num::cast(self.n).unwrap();
+        let three_halves = num::cast::<f32, T>(1.5).unwrap();
+        n.sqrt() * self.m3 / self.m2.powf(three_halves)
+    }
+
+    /// The running excess population kurtosis, `n * M4 / M2^2 - 3`. Requires at least one
+    /// value; constant samples have zero variance and yield `NaN`.
+    pub fn kurtosis(&self) -> T {
+        assert!(self.n > 0, "kurtosis requires at least one data point");
+        let n: T = num::cast(self.n).unwrap();
+        let three = num::cast::<f32, T>(3.0).unwrap();
+        n * self.m4 / (self.m2 * self.m2) - three
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_running_stats() {
+        // stream the same sample used by the batch skewness/kurtosis tests and check the
+        // online moments agree with the documented closed-form values.
+        let v = vec![1.25, 1.5, 1.5, 1.75, 1.75, 2.5, 2.75, 4.5];
+        let mut rs = RunningStats::new();
+        assert!(rs.is_empty());
+        for &x in &v {
+            rs.push(x);
+        }
+        assert_eq!(rs.len(), v.len());
+        let epsilon = 1e-6;
+        assert!((rs.mean() - 2.1875).abs() < epsilon);
+        assert!((rs.skewness() - 1.3747465025469285).abs() < epsilon);
+        assert!((rs.kurtosis() - 0.7794232987312579).abs() < epsilon);
+    }
+}
@@ -22,6 +22,7 @@ use num::{Float,
           Num,
           NumCast,
           One,
+          PrimInt,
           Zero};
 
 
@@ -61,15 +62,106 @@ pub fn median<T>(v: &[T]) -> T
     where T: Copy + Num + NumCast + PartialOrd
 {
     assert!(v.len() > 0);
-    let mut scratch: Vec<&T> = Vec::with_capacity(v.len());
-    scratch.extend(v.iter());
-    quicksort(&mut scratch);
+    let mut scratch: Vec<T> = v.to_vec();
 
     let mid = scratch.len() / 2;
     if scratch.len() % 2 == 1 {
-        *scratch[mid]
+        select_nth(&mut scratch, mid)
     } else {
-        (*scratch[mid] + *scratch[mid-1]) / num::cast(2).unwrap()
+        let hi = select_nth(&mut scratch, mid);
+        let lo = select_nth(&mut scratch, mid - 1);
+        (lo + hi) / num::cast(2).unwrap()
+    }
+}
+
+/// Return the `k`-th order statistic (zero-indexed) of `v`, partially reordering `v` in
+/// place. Reuses the randomized `partition`/`select_pivot` machinery but only recurses
+/// into the side containing the sought element, giving O(n) average-case selection.
+/// (reference)[http://en.wikipedia.org/wiki/Quickselect]
+pub fn select_nth<T>(v: &mut [T], k: usize) -> T
+    where T: PartialOrd + Copy
+{
+    assert!(k < v.len(), "select_nth index out of bounds");
+    let pivot = partition(v);
+    if k == pivot {
+        v[pivot]
+    } else if k < pivot {
+        select_nth(&mut v[..pivot], k)
+    } else {
+        select_nth(&mut v[(pivot+1)..], k - pivot - 1)
+    }
+}
+
+/// The overflow-free floor average of two integers, `avg(a,b) = (a & b) + ((a ^ b) >> 1)`,
+/// which never overflows even when `a` and `b` are near `T::MAX`.
+#[inline(always)]
+fn floor_average<T>(a: T, b: T) -> T
+    where T: PrimInt
+{
+    (a & b) + ((a ^ b) >> 1)
+}
+
+/// Arithmetic mean of an integer slice that never overflows the accumulator. Rather than
+/// summing every element into one value, it adds up each element's quotient `x / n` and
+/// remainder `x % n` separately, carrying whole multiples of `n` from the remainder into the
+/// quotient so no precision is lost. The result is floored toward negative infinity.
+pub fn integer_mean<T>(v: &[T]) -> T
+    where T: PrimInt
+{
+    assert!(!v.is_empty(), "integer_mean requires at least one data point");
+    let mut avg = T::zero();
+    let mut rem = T::zero();
+    let len: T = num::cast(v.len()).unwrap();
+    for &x in v {
+        rem = rem + x % len;
+        avg = avg + x / len + rem / len;
+        rem = rem % len;
+    }
+    if rem < T::zero() {
+        avg = avg - T::one();
+    }
+    avg
+}
+
+/// Like `integer_mean`, but rounds the result to nearest (halves round up) using the
+/// remainder left over after the last element instead of flooring.
+pub fn integer_mean_round<T>(v: &[T]) -> T
+    where T: PrimInt
+{
+    assert!(!v.is_empty(), "integer_mean_round requires at least one data point");
+    let mut avg = T::zero();
+    let mut rem = T::zero();
+    let len: T = num::cast(v.len()).unwrap();
+    for &x in v {
+        rem = rem + x % len;
+        avg = avg + x / len + rem / len;
+        rem = rem % len;
+    }
+    if rem < T::zero() {
+        avg = avg - T::one();
+        rem = rem + len;
+    }
+    if rem >= len - rem {
+        avg = avg + T::one();
+    }
+    avg
+}
+
+/// Overflow-free integer median. Selects the middle order statistic for odd lengths and the
+/// overflow-safe floor average of the two middle order statistics for even lengths, reusing
+/// the quickselect `select_nth` and the `floor_average` identity.
+pub fn integer_median<T>(v: &[T]) -> T
+    where T: PrimInt
+{
+    assert!(!v.is_empty(), "integer_median requires at least one data point");
+    let mut scratch: Vec<T> = v.to_vec();
+    let mid = scratch.len() / 2;
+    if scratch.len() % 2 == 1 {
+        select_nth(&mut scratch, mid)
+    } else {
+        let hi = select_nth(&mut scratch, mid);
+        let lo = select_nth(&mut scratch, mid - 1);
+        floor_average(lo, hi)
     }
 }
 
@@ -137,6 +229,29 @@ pub fn standard_scores<T>(v: &[T]) -> Vec<T>
     return scores;
 }
 
+/// The value below which a given percentage of the data falls, computed by sorting the data
+/// and linearly interpolating between the two closest ranks. `pct` is given on a 0..100 scale.
+/// (reference)[http://en.wikipedia.org/wiki/Percentile]
+pub fn percentile<T>(v: &[T], pct: T) -> T
+    where T: Float
+{
+    assert!(!v.is_empty(), "percentile requires at least one data point");
+    let mut scratch: Vec<T> = v.to_vec();
+    quicksort(&mut scratch);
+
+    let len: T = num::cast(scratch.len()).unwrap();
+    let hundred: T = num::cast(100).unwrap();
+    let rank = (pct / hundred) * (len - T::one());
+    let lower = rank.floor();
+    let frac = rank - lower;
+    let idx = num::cast::<T, usize>(lower).unwrap();
+    if idx + 1 >= scratch.len() {
+        scratch[scratch.len() - 1]
+    } else {
+        scratch[idx] + frac * (scratch[idx + 1] - scratch[idx])
+    }
+}
+
 #[inline(always)]
 fn select_pivot<T>(v: &mut [T])
     where T: Copy
@@ -205,6 +320,45 @@ mod tests {
         assert_eq!(median(&vec), 4.0);
     }
 
+    #[test]
+    fn test_select_nth() {
+        let mut vec = vec![5.0, 1.0, 4.0, 2.0, 3.0];
+        assert_eq!(select_nth(&mut vec, 0), 1.0);
+        let mut vec = vec![5.0, 1.0, 4.0, 2.0, 3.0];
+        assert_eq!(select_nth(&mut vec, 2), 3.0);
+        let mut vec = vec![5.0, 1.0, 4.0, 2.0, 3.0];
+        assert_eq!(select_nth(&mut vec, 4), 5.0);
+    }
+
+    #[test]
+    fn test_integer_mean() {
+        let vec = vec![2, 4, 6, 8];
+        assert_eq!(integer_mean(&vec), 5);
+        // mixed >=3 element case: 20 / 5 == 4
+        let vec = vec![1, 2, 3, 4, 10];
+        assert_eq!(integer_mean(&vec), 4);
+        // floors towards negative infinity and rounds to nearest respectively
+        let vec = vec![1, 2];
+        assert_eq!(integer_mean(&vec), 1);
+        assert_eq!(integer_mean_round(&vec), 2);
+        let vec = vec![1, 2, 3, 4];
+        assert_eq!(integer_mean(&vec), 2);
+        assert_eq!(integer_mean_round(&vec), 3);
+        // does not overflow the accumulator near T::MAX
+        let vec = vec![i64::max_value(), i64::max_value()];
+        assert_eq!(integer_mean(&vec), i64::max_value());
+    }
+
+    #[test]
+    fn test_integer_median() {
+        let vec = vec![1, 3, 5];
+        assert_eq!(integer_median(&vec), 3);
+        let vec = vec![1, 2, 3, 4];
+        assert_eq!(integer_median(&vec), 2);
+        let vec = vec![i64::max_value(), i64::max_value()];
+        assert_eq!(integer_median(&vec), i64::max_value());
+    }
+
     #[test]
     fn test_variance() {
         let v = vec![0.0, 0.25, 0.25, 1.25, 1.5, 1.75, 2.75, 3.25];
@@ -121,6 +121,62 @@ pub fn pkurtosis<T>(v: &[T], mean: Option<T>, pstdev: Option<T>) -> T
     m / num::cast(v.len()).unwrap() - num::cast(3).unwrap()
 }
 
+/// A kernel density estimator fit to a sample, giving a smooth estimate of the
+/// probability density at arbitrary query points using a Gaussian kernel.
+/// (reference)[http://en.wikipedia.org/wiki/Kernel_density_estimation]
+pub struct KernelDensityEstimator<T> {
+    samples: Vec<T>,
+    bandwidth: T,
+}
+
+impl<T> KernelDensityEstimator<T>
+    where T: Float
+{
+    /// Fit an estimator to `v`, choosing the bandwidth by Silverman's rule of thumb:
+    /// `h = 0.9 * min(sigma, IQR/1.34) * n^(-1/5)`.
+    pub fn new(v: &[T]) -> KernelDensityEstimator<T> {
+        let bandwidth = silverman_bandwidth(v);
+        KernelDensityEstimator { samples: v.to_vec(), bandwidth }
+    }
+
+    /// Fit an estimator to `v` with an explicit bandwidth override.
+    pub fn with_bandwidth(v: &[T], bandwidth: T) -> KernelDensityEstimator<T> {
+        KernelDensityEstimator { samples: v.to_vec(), bandwidth }
+    }
+
+    /// Estimate the probability density at a single point `x`.
+    pub fn estimate(&self, x: T) -> T {
+        let n: T = num::cast(self.samples.len()).unwrap();
+        let sum = self.samples.iter()
+            .map(|&xi| gaussian_kernel((x - xi) / self.bandwidth))
+            .fold(T::zero(), |acc, elem| acc + elem);
+        sum / (n * self.bandwidth)
+    }
+
+    /// Estimate the probability density at each point in `xs`.
+    pub fn estimate_many(&self, xs: &[T]) -> Vec<T> {
+        xs.iter().map(|&x| self.estimate(x)).collect()
+    }
+}
+
+fn gaussian_kernel<T>(u: T) -> T
+    where T: Float
+{
+    let two = T::one() + T::one();
+    let inv_sqrt_2pi = T::one() / (two * T::from(std::f64::consts::PI).unwrap()).sqrt();
+    inv_sqrt_2pi * (-(u * u) / two).exp()
+}
+
+fn silverman_bandwidth<T>(v: &[T]) -> T
+    where T: Float
+{
+    let n: T = num::cast(v.len()).unwrap();
+    let sigma = stats::population_standard_deviation(v, None);
+    let iqr = stats::percentile(v, num::cast(75).unwrap()) - stats::percentile(v, num::cast(25).unwrap());
+    let spread = sigma.min(iqr / num::cast(1.34).unwrap());
+    num::cast::<f64, T>(0.9).unwrap() * spread * n.powf(-T::one() / num::cast(5).unwrap())
+}
+
 pub fn standard_error_mean<T>(stdev: T, sample_size: T, population_size: Option<T>) -> T
     where T: Float
 {
@@ -222,6 +278,20 @@ mod test {
         let vec = vec![1.25, 1.5, 1.5, 1.75, 1.75, 2.5, 2.75, 4.5];
         assert_eq!(pkurtosis(&vec, None, None), 0.7794232987312579);
     }
+    #[test]
+    fn test_kernel_density_estimator() {
+        // with a single sample at the origin and unit bandwidth the estimate at the origin is
+        // just the Gaussian kernel at zero, 1/sqrt(2*pi).
+        let kde = KernelDensityEstimator::with_bandwidth(&[0.0], 1.0);
+        let epsilon = 1e-9;
+        assert!((kde.estimate(0.0) - 0.3989422804014327).abs() < epsilon);
+        // two samples straddling the origin: each contributes K(1)/2 at x = 0.
+        let kde = KernelDensityEstimator::with_bandwidth(&[-1.0, 1.0], 1.0);
+        let expected = 0.3989422804014327 * (-0.5f64).exp();
+        assert!((kde.estimate(0.0) - expected).abs() < epsilon);
+        assert_eq!(kde.estimate_many(&[0.0, 0.0]), vec![kde.estimate(0.0), kde.estimate(0.0)]);
+    }
+
     #[test]
     fn test_standard_error_mean() {
         assert_eq!(standard_error_mean(2.0, 16.0, None), 0.5);